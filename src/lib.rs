@@ -6,6 +6,10 @@ pub enum Output {
     #[default]
     Text,
     Json,
+    /// Returns the full `Response` object instead of just the decoded body.
+    Response,
+    /// Returns the raw response body as a `Blob`, without attempting to decode it as text.
+    Bytes,
 }
 
 #[derive(Clone, serde::Deserialize)]
@@ -18,6 +22,82 @@ pub struct Parameters {
     body: rhai::Dynamic,
     #[serde(default)]
     output: Output,
+    /// Overrides the client's default timeout for this request only.
+    #[serde(default)]
+    timeout_ms: Option<u64>,
+    #[serde(default)]
+    body_type: BodyType,
+    #[serde(default)]
+    auth: Option<Auth>,
+    /// Query parameters to append to `url`, instead of hand-concatenating `?a=1&b=2`.
+    #[serde(default)]
+    query: Option<std::collections::BTreeMap<String, String>>,
+}
+
+/// Credentials to attach to a request's `Authorization` header, keeping them out of the
+/// free-form `headers` array.
+#[derive(Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Auth {
+    /// Sets `Authorization: Bearer <token>`.
+    Bearer(String),
+    /// Sets `Authorization: Basic <base64(username:password)>`.
+    Basic(BasicAuth),
+}
+
+#[derive(Clone, serde::Deserialize)]
+pub struct BasicAuth {
+    username: String,
+    password: String,
+}
+
+/// How `body` should be interpreted and serialized onto the request.
+#[derive(Default, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BodyType {
+    /// Send `body` unchanged, as a string or a `Blob`.
+    #[default]
+    Raw,
+    /// Serialize `body` as a JSON document and set the `Content-Type` header accordingly.
+    Json,
+    /// Serialize a map of string to string as `application/x-www-form-urlencoded`.
+    Form,
+    /// Build a `multipart/form-data` body from an array of field maps.
+    Multipart,
+}
+
+/// A single field of a `multipart` body, either a file (`bytes` set) or a plain text value.
+#[derive(Clone, serde::Deserialize)]
+pub struct MultipartField {
+    name: String,
+    #[serde(default)]
+    filename: Option<String>,
+    #[serde(default)]
+    bytes: rhai::Dynamic,
+    #[serde(default)]
+    text: Option<String>,
+}
+
+/// Options accepted by `http::client` to configure the underlying `reqwest` client.
+#[derive(Default, Clone, serde::Deserialize)]
+pub struct ClientParameters {
+    #[serde(default)]
+    timeout_ms: Option<u64>,
+    #[serde(default)]
+    connect_timeout_ms: Option<u64>,
+    /// Maximum number of redirects to follow. Ignored if `redirect` is set.
+    #[serde(default)]
+    max_redirects: Option<usize>,
+    /// Set to `"none"` to disable following redirects entirely.
+    #[serde(default)]
+    redirect: Option<Redirect>,
+}
+
+#[derive(Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Redirect {
+    /// Disable following redirects entirely.
+    None,
 }
 
 #[export_module]
@@ -29,6 +109,84 @@ pub mod rhai_http {
     /// # rhai-autodocs:index:1
     pub type Client = reqwest::blocking::Client;
 
+    /// A buffered HTTP response, returned when `output: "response"` is passed to `request`.
+    ///
+    /// The body is read eagerly when the response is received, so `text()` and `json()` can
+    /// both be called (and `status()`/`headers()` read) any number of times.
+    ///
+    /// # rhai-autodocs:index:3
+    #[derive(Clone)]
+    pub struct Response {
+        status: reqwest::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: Vec<u8>,
+    }
+
+    /// The HTTP status code of the response, e.g. `200` or `404`.
+    ///
+    /// # rhai-autodocs:index:4
+    #[rhai_fn(global, pure)]
+    pub fn status(response: &mut Response) -> i64 {
+        response.status.as_u16() as i64
+    }
+
+    /// The canonical reason phrase of the response's status code, e.g. `"OK"` or `"Not Found"`.
+    ///
+    /// # rhai-autodocs:index:4
+    #[rhai_fn(global, pure)]
+    pub fn status_text(response: &mut Response) -> String {
+        response
+            .status
+            .canonical_reason()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    /// The response headers, as a map of header name to value.
+    ///
+    /// # rhai-autodocs:index:4
+    #[rhai_fn(global, pure)]
+    pub fn headers(response: &mut Response) -> rhai::Map {
+        response
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.as_str().into(),
+                    value.to_str().unwrap_or_default().into(),
+                )
+            })
+            .collect()
+    }
+
+    /// The response body, decoded as UTF-8 text.
+    ///
+    /// # Errors
+    ///
+    /// - The body is not valid UTF-8
+    ///
+    /// # rhai-autodocs:index:4
+    #[rhai_fn(global, pure, return_raw)]
+    pub fn text(response: &mut Response) -> Result<String, Box<rhai::EvalAltResult>> {
+        String::from_utf8(response.body.clone()).map_err(|error| error.to_string().into())
+    }
+
+    /// The response body, decoded as JSON.
+    ///
+    /// # Errors
+    ///
+    /// - The body is not valid JSON
+    ///
+    /// # rhai-autodocs:index:4
+    #[rhai_fn(global, pure, return_raw)]
+    pub fn json(response: &mut Response) -> Result<rhai::Dynamic, Box<rhai::EvalAltResult>> {
+        serde_json::from_slice::<serde_json::Value>(&response.body)
+            .map_err::<Box<rhai::EvalAltResult>, _>(|error| error.to_string().into())
+            .and_then(|value| {
+                rhai::serde::to_dynamic(value).map_err(|error| error.to_string().into())
+            })
+    }
+
     /// Create a new HTTP client. Can be used to query HTTP endpoints.
     ///
     /// # Errors
@@ -50,6 +208,61 @@ pub mod rhai_http {
             .map_err(|error| error.to_string().into())
     }
 
+    /// Create a new HTTP client with options.
+    ///
+    /// # Args
+    ///
+    /// - `parameters`: A map of parameters with the following fields:
+    ///     - `timeout_ms`: Optional default timeout applied to every request sent by this client.
+    ///     - `connect_timeout_ms`: Optional timeout for the connection phase only.
+    ///     - `max_redirects`: Optional maximum number of redirects to follow. Defaults to reqwest's built-in limit.
+    ///     - `redirect`: Set to `"none"` to disable following redirects entirely. Takes precedence over `max_redirects`.
+    ///
+    /// # Errors
+    ///
+    /// - TLS backend could not be initialized
+    /// - Resolver could not load the system configuration
+    ///
+    /// # Example
+    ///
+    /// ```js
+    /// let client = http::client(#{ timeout_ms: 5000 });
+    /// ```
+    ///
+    /// # rhai-autodocs:index:2
+    #[rhai_fn(name = "client", return_raw)]
+    pub fn client_with_options(parameters: rhai::Map) -> Result<Client, Box<rhai::EvalAltResult>> {
+        let ClientParameters {
+            timeout_ms,
+            connect_timeout_ms,
+            max_redirects,
+            redirect,
+        } = rhai::serde::from_dynamic::<ClientParameters>(&parameters.into())?;
+
+        let mut builder = reqwest::blocking::Client::builder();
+
+        if let Some(timeout_ms) = timeout_ms {
+            builder = builder.timeout(std::time::Duration::from_millis(timeout_ms));
+        }
+
+        if let Some(connect_timeout_ms) = connect_timeout_ms {
+            builder = builder.connect_timeout(std::time::Duration::from_millis(connect_timeout_ms));
+        }
+
+        match redirect {
+            Some(Redirect::None) => {
+                builder = builder.redirect(reqwest::redirect::Policy::none());
+            }
+            None => {
+                if let Some(max_redirects) = max_redirects {
+                    builder = builder.redirect(reqwest::redirect::Policy::limited(max_redirects));
+                }
+            }
+        }
+
+        builder.build().map_err(|error| error.to_string().into())
+    }
+
     /// Execute an HTTP request.
     ///
     /// # Args
@@ -58,8 +271,12 @@ pub mod rhai_http {
     ///     - `method`: the method to use. (e.g. "POST", "GET", etc.)
     ///     - `url`: Endpoint to query.
     ///     - `headers`: Optional headers to add to the query.
-    ///     - `body`: Optional body to add to the query.
-    ///     - `output`: Output format of the response retrieved by the client, can either be 'text' or 'json'. Defaults to 'text'.
+    ///     - `body`: Optional body to add to the query, either a string or a `Blob` (sent unchanged as binary).
+    ///     - `body_type`: How to interpret `body`: 'json' to serialize it as JSON, 'form' to serialize a string-to-string map as `application/x-www-form-urlencoded`, 'multipart' to build a `multipart/form-data` body from an array of `#{ name, filename, bytes }` or `#{ name, text }` maps. Defaults to sending `body` unchanged.
+    ///     - `output`: Output format of the response retrieved by the client, can be 'text', 'json', 'bytes' or 'response' (the full `Response` object). Defaults to 'text'.
+    ///     - `timeout_ms`: Optional timeout for this request, overriding the client's default.
+    ///     - `auth`: Optional credentials, either `#{ bearer: "token" }` or `#{ basic: #{ username: "u", password: "p" } }`. Cannot be combined with a manual `Authorization` entry in `headers`.
+    ///     - `query`: Optional map of query parameters, appended to `url` and properly encoded.
     ///
     /// # Errors
     ///
@@ -92,46 +309,141 @@ pub mod rhai_http {
             headers,
             body,
             output,
+            timeout_ms,
+            body_type,
+            auth,
+            query,
         } = rhai::serde::from_dynamic::<Parameters>(&parameters.into())?;
 
         let method = reqwest::Method::from_str(&method)
             .map_err::<Box<rhai::EvalAltResult>, _>(|error| error.to_string().into())?;
 
-        client
-            .request(method, url)
-            .headers(
-                headers
-                    .iter()
-                    .map(|header| {
-                        if let Some((name, value)) = header.to_string().split_once(':') {
-                            let name = name.trim();
-                            let value = value.trim();
-
-                            let name = reqwest::header::HeaderName::from_str(name).map_err::<Box<
-                                EvalAltResult,
-                            >, _>(
-                                |error| error.to_string().into(),
-                            )?;
-                            let value = reqwest::header::HeaderValue::from_str(value)
-                                .map_err::<Box<EvalAltResult>, _>(|error| {
-                                    error.to_string().into()
-                                })?;
-
-                            Ok((name, value))
-                        } else {
-                            Err(format!("'{header}' is not a valid header").into())
+        let mut request = client.request(method, url);
+
+        if let Some(query) = query {
+            request = request.query(&query);
+        }
+
+        if let Some(timeout_ms) = timeout_ms {
+            request = request.timeout(std::time::Duration::from_millis(timeout_ms));
+        }
+
+        let has_auth = auth.is_some();
+
+        let request = match auth {
+            Some(Auth::Bearer(token)) => request.bearer_auth(token),
+            Some(Auth::Basic(BasicAuth { username, password })) => {
+                request.basic_auth(username, Some(password))
+            }
+            None => request,
+        };
+
+        let request = request.headers(
+            headers
+                .iter()
+                .map(|header| {
+                    if let Some((name, value)) = header.to_string().split_once(':') {
+                        let name = name.trim();
+                        let value = value.trim();
+
+                        let name = reqwest::header::HeaderName::from_str(name)
+                            .map_err::<Box<EvalAltResult>, _>(|error| error.to_string().into())?;
+
+                        if has_auth && name == reqwest::header::AUTHORIZATION {
+                            return Err(
+                                "cannot set an 'Authorization' header manually when 'auth' is also provided"
+                                    .into(),
+                            );
                         }
-                    })
-                    .collect::<Result<reqwest::header::HeaderMap, Box<EvalAltResult>>>()?,
-            )
-            // FIXME: string or blob.
-            .body(body.to_string())
+
+                        let value = reqwest::header::HeaderValue::from_str(value).map_err::<Box<
+                            EvalAltResult,
+                        >, _>(
+                            |error| error.to_string().into(),
+                        )?;
+
+                        Ok((name, value))
+                    } else {
+                        Err(format!("'{header}' is not a valid header").into())
+                    }
+                })
+                .collect::<Result<reqwest::header::HeaderMap, Box<EvalAltResult>>>()?,
+        );
+
+        let request = match body_type {
+            BodyType::Raw => request.body(if body.is::<rhai::Blob>() {
+                reqwest::blocking::Body::from(body.cast::<rhai::Blob>())
+            } else {
+                reqwest::blocking::Body::from(body.to_string())
+            }),
+            BodyType::Json => {
+                let value = rhai::serde::from_dynamic::<serde_json::Value>(&body)?;
+                request.json(&value)
+            }
+            BodyType::Form => {
+                let fields =
+                    rhai::serde::from_dynamic::<std::collections::BTreeMap<String, String>>(&body)?;
+                request.form(&fields)
+            }
+            BodyType::Multipart => {
+                let fields = rhai::serde::from_dynamic::<Vec<MultipartField>>(&body)?;
+                let mut form = reqwest::blocking::multipart::Form::new();
+
+                for field in fields {
+                    let part = if field.bytes.is::<rhai::Blob>() {
+                        let part = reqwest::blocking::multipart::Part::bytes(
+                            field.bytes.cast::<rhai::Blob>(),
+                        );
+
+                        match field.filename {
+                            Some(filename) => part.file_name(filename),
+                            None => part,
+                        }
+                    } else if let Some(text) = field.text {
+                        reqwest::blocking::multipart::Part::text(text)
+                    } else {
+                        return Err(format!(
+                            "multipart field '{}' must have either 'bytes' or 'text'",
+                            field.name
+                        )
+                        .into());
+                    };
+
+                    form = form.part(field.name, part);
+                }
+
+                request.multipart(form)
+            }
+        };
+
+        request
             .send()
             .and_then(|response| match output {
                 Output::Text => response.text().map(rhai::Dynamic::from),
                 Output::Json => response.json::<rhai::Map>().map(rhai::Dynamic::from),
+                Output::Bytes => response
+                    .bytes()
+                    .map(|body| rhai::Dynamic::from_blob(body.to_vec())),
+                Output::Response => {
+                    let status = response.status();
+                    let headers = response.headers().clone();
+
+                    response.bytes().map(|body| {
+                        rhai::Dynamic::from(Response {
+                            status,
+                            headers,
+                            body: body.to_vec(),
+                        })
+                    })
+                }
+            })
+            .map_err(|error| {
+                if error.is_timeout() {
+                    "request timed out".into()
+                } else {
+                    error.to_string().into()
+                }
             })
-            .map_err(|error| error.to_string().into())
     }
 }
 
@@ -149,6 +461,115 @@ pub mod test {
     use crate::HttpPackage;
     use rhai::packages::Package;
 
+    /// Finds the offset of `needle` in `haystack`, used to locate the end of HTTP headers.
+    fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        haystack
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    /// Spins up a one-shot local HTTP server: accepts a single connection, waits for a full
+    /// request (headers plus any body announced by `Content-Length`), sleeps for `delay` before
+    /// replying with the raw `response` bytes, then sends the captured request back over the
+    /// returned channel. Used to test timeouts and to assert what a request actually sent.
+    fn one_shot_server(
+        delay: std::time::Duration,
+        response: &'static str,
+    ) -> (std::net::SocketAddr, std::sync::mpsc::Receiver<Vec<u8>>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+
+                request.extend_from_slice(&buffer[..read]);
+
+                if let Some(header_end) = find_subslice(&request, b"\r\n\r\n") {
+                    let content_length = String::from_utf8_lossy(&request[..header_end])
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length:")
+                                .map(|value| value.trim().to_string())
+                        })
+                        .and_then(|value| value.parse::<usize>().ok())
+                        .unwrap_or(0);
+
+                    if request.len() >= header_end + 4 + content_length {
+                        break;
+                    }
+                }
+            }
+
+            std::thread::sleep(delay);
+            let _ = stream.write_all(response.as_bytes());
+            let _ = tx.send(request);
+        });
+
+        (addr, rx)
+    }
+
+    #[test]
+    fn request_timeout_errors() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let (addr, _request) = one_shot_server(
+            std::time::Duration::from_millis(300),
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+        );
+
+        let error = engine
+            .eval::<String>(&format!(
+                r#"
+let client = http::client();
+
+client.request(#{{ method: "GET", url: "http://{addr}", timeout_ms: 50 }})"#
+            ))
+            .unwrap_err();
+
+        assert!(
+            error.to_string().contains("timed out"),
+            "expected a timeout error, got: {error}"
+        );
+    }
+
+    #[test]
+    fn request_timeout_overrides_client_timeout() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let (addr, _request) = one_shot_server(
+            std::time::Duration::from_millis(200),
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+        );
+
+        let body: String = engine
+            .eval(&format!(
+                r#"
+let client = http::client(#{{ timeout_ms: 50 }});
+
+client.request(#{{ method: "GET", url: "http://{addr}", timeout_ms: 1000 }})"#
+            ))
+            .unwrap();
+
+        assert_eq!(body, "ok");
+    }
+
     #[test]
     fn simple_query() {
         let mut engine = rhai::Engine::new();
@@ -242,4 +663,262 @@ client.request(#{
 
         println!("{body:#?}");
     }
+
+    #[test]
+    fn form_body() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let (addr, request) = one_shot_server(
+            std::time::Duration::ZERO,
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+        );
+
+        let body: String = engine
+            .eval(&format!(
+                r#"
+let client = http::client();
+
+client.request(#{{
+    method: "POST",
+    url: "http://{addr}",
+    body_type: "form",
+    body: #{{ "a": "1", "b": "two words" }},
+}})"#
+            ))
+            .unwrap();
+
+        assert_eq!(body, "ok");
+
+        let request = String::from_utf8_lossy(&request.recv().unwrap()).to_string();
+
+        assert!(
+            request
+                .to_lowercase()
+                .contains("content-type: application/x-www-form-urlencoded"),
+            "request did not carry a form content-type:\n{request}"
+        );
+        assert!(
+            request.contains("a=1&b=two+words"),
+            "request body was not urlencoded as expected:\n{request}"
+        );
+    }
+
+    #[test]
+    fn multipart_body() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let (addr, request) = one_shot_server(
+            std::time::Duration::ZERO,
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+        );
+
+        let mut scope = rhai::Scope::new();
+        scope.push_constant_dynamic(
+            "payload",
+            rhai::Dynamic::from_blob(b"file contents".to_vec()),
+        );
+
+        let body: String = engine
+            .eval_with_scope(
+                &mut scope,
+                &format!(
+                    r#"
+let client = http::client();
+
+client.request(#{{
+    method: "POST",
+    url: "http://{addr}",
+    body_type: "multipart",
+    body: [
+        #{{ name: "field", text: "value" }},
+        #{{ name: "file", filename: "a.bin", bytes: payload }},
+    ],
+}})"#
+                ),
+            )
+            .unwrap();
+
+        assert_eq!(body, "ok");
+
+        let request = String::from_utf8_lossy(&request.recv().unwrap()).to_string();
+
+        assert!(
+            request
+                .to_lowercase()
+                .contains("content-type: multipart/form-data"),
+            "request did not carry a multipart content-type:\n{request}"
+        );
+        assert!(request.contains("name=\"field\""));
+        assert!(request.contains("value"));
+        assert!(request.contains("name=\"file\""));
+        assert!(request.contains("filename=\"a.bin\""));
+        assert!(request.contains("file contents"));
+    }
+
+    #[test]
+    fn multipart_field_without_bytes_or_text_errors() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let error = engine
+            .eval::<rhai::Dynamic>(
+                r#"
+let client = http::client();
+
+client.request(#{
+    method: "POST",
+    url: "http://127.0.0.1:1",
+    body_type: "multipart",
+    body: [
+        #{ name: "oops" },
+    ],
+})"#,
+            )
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("must have either 'bytes' or 'text'"),
+            "unexpected error:\n{error}"
+        );
+    }
+
+    #[test]
+    fn bearer_auth() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let (addr, request) = one_shot_server(
+            std::time::Duration::ZERO,
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+        );
+
+        let body: String = engine
+            .eval(&format!(
+                r#"
+let client = http::client();
+
+client.request(#{{ method: "GET", url: "http://{addr}", auth: #{{ bearer: "token123" }} }})"#
+            ))
+            .unwrap();
+
+        assert_eq!(body, "ok");
+
+        let request = String::from_utf8_lossy(&request.recv().unwrap()).to_string();
+
+        assert!(
+            request.contains("Bearer token123"),
+            "request did not carry the expected bearer token:\n{request}"
+        );
+    }
+
+    #[test]
+    fn basic_auth() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let (addr, request) = one_shot_server(
+            std::time::Duration::ZERO,
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+        );
+
+        let body: String = engine
+            .eval(&format!(
+                r#"
+let client = http::client();
+
+client.request(#{{
+    method: "GET",
+    url: "http://{addr}",
+    auth: #{{ basic: #{{ username: "user", password: "pass" }} }},
+}})"#
+            ))
+            .unwrap();
+
+        assert_eq!(body, "ok");
+
+        let request = String::from_utf8_lossy(&request.recv().unwrap()).to_string();
+
+        // base64("user:pass"), must be checked case-sensitively
+        assert!(
+            request.contains("Basic dXNlcjpwYXNz"),
+            "request did not carry the expected basic auth header:\n{request}"
+        );
+    }
+
+    #[test]
+    fn auth_conflicts_with_manual_authorization_header() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let error = engine
+            .eval::<rhai::Dynamic>(
+                r#"
+let client = http::client();
+
+client.request(#{
+    method: "GET",
+    url: "http://127.0.0.1:1",
+    auth: #{ bearer: "token123" },
+    headers: ["Authorization: Bearer other-token"],
+})"#,
+            )
+            .unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("cannot set an 'Authorization' header"),
+            "unexpected error:\n{error}"
+        );
+    }
+
+    #[test]
+    fn redirect_none_stops_following() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let (addr, _request) = one_shot_server(
+            std::time::Duration::ZERO,
+            "HTTP/1.1 302 Found\r\nLocation: http://example.invalid\r\nContent-Length: 0\r\n\r\n",
+        );
+
+        let status: i64 = engine
+            .eval(&format!(
+                r#"
+let client = http::client(#{{ redirect: "none" }});
+
+let response = client.request(#{{ method: "GET", url: "http://{addr}", output: "response" }});
+response.status()"#
+            ))
+            .unwrap();
+
+        assert_eq!(status, 302);
+    }
+
+    #[test]
+    fn redirect_rejects_unknown_value() {
+        let mut engine = rhai::Engine::new();
+
+        HttpPackage::new().register_into_engine(&mut engine);
+
+        let error = engine
+            .eval::<rhai::Dynamic>(r#"http::client(#{ redirect: "noen" })"#)
+            .unwrap_err();
+
+        assert!(
+            error.to_string().contains("noen"),
+            "expected the unrecognized redirect value to be reported, got: {error}"
+        );
+    }
 }